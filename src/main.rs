@@ -3,25 +3,180 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use clap::{Parser, ValueEnum};
 use num_cpus;
+use regex::Regex;
+use serde::Serialize;
+use std::fs::File;
 use std::io;
+use std::io::Write as IoWrite;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::thread;
 use std::time::{Duration, Instant};
-use sysinfo::{CpuExt, System, SystemExt};
+use sysinfo::{ComponentExt, CpuExt, PidExt, ProcessExt, System, SystemExt};
 use tui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols,
     text::{Span, Spans},
-    widgets::{Axis, Block, Borders, Chart, Dataset, Gauge, List, ListItem, Paragraph},
+    widgets::{Axis, Block, Borders, Chart, Dataset, Gauge, List, ListItem, ListState, Paragraph},
     Terminal,
 };
 
 const TARGET_OPERATIONS: u64 = 100_000_000_000; // 1 trillion operations
 
+/// Unit the temperature panel displays sensor readings in. Sensors are always
+/// stored internally in Celsius (as reported by `sysinfo`) and converted for
+/// display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum TemperatureType {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl TemperatureType {
+    /// Convert a Celsius reading into this unit.
+    fn convert(&self, celsius: f32) -> f32 {
+        match self {
+            TemperatureType::Celsius => celsius,
+            TemperatureType::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            TemperatureType::Kelvin => celsius + 273.15,
+        }
+    }
+
+    /// One-character suffix shown after a converted reading.
+    fn suffix(&self) -> char {
+        match self {
+            TemperatureType::Celsius => 'C',
+            TemperatureType::Fahrenheit => 'F',
+            TemperatureType::Kelvin => 'K',
+        }
+    }
+
+    /// Next unit in the Celsius → Fahrenheit → Kelvin cycle, for the `t` key.
+    fn next_unit(&self) -> TemperatureType {
+        match self {
+            TemperatureType::Celsius => TemperatureType::Fahrenheit,
+            TemperatureType::Fahrenheit => TemperatureType::Kelvin,
+            TemperatureType::Kelvin => TemperatureType::Celsius,
+        }
+    }
+}
+
+/// Runtime configuration, parsed from the command line, that replaces the
+/// previously hard-coded workload constants in `main`.
+#[derive(Parser, Debug)]
+#[command(author, version, about = "A terminal CPU/memory stress-test benchmark")]
+struct Config {
+    /// Total number of operations to run before stopping.
+    #[arg(long, default_value_t = TARGET_OPERATIONS)]
+    operations: u64,
+
+    /// Number of worker threads to spawn (defaults to the detected core count).
+    #[arg(long, default_value_t = num_cpus::get())]
+    threads: usize,
+
+    /// Poll/redraw interval in milliseconds.
+    #[arg(long, default_value_t = 200)]
+    refresh_ms: u64,
+
+    /// Number of samples retained in the CPU/memory history.
+    #[arg(long, default_value_t = 240)]
+    history: usize,
+
+    /// Unit the temperature panel initially displays sensor readings in.
+    #[arg(long, value_enum, default_value_t = TemperatureType::Celsius)]
+    temperature_unit: TemperatureType,
+
+    /// Temperature in Celsius above which a sensor is highlighted in red.
+    #[arg(long, default_value_t = 80.0)]
+    temp_threshold: f32,
+
+    /// Skip the TUI and only print the final score summary.
+    #[arg(long)]
+    headless: bool,
+
+    /// Write a structured result record to this path on completion. The format
+    /// is taken from `--format`, falling back to the file extension.
+    #[arg(long)]
+    export: Option<PathBuf>,
+
+    /// Result format for `--export`. Defaults to the export path's extension.
+    #[arg(long, value_enum)]
+    format: Option<ExportFormat>,
+}
+
+/// Serialization format for exported benchmark results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ExportFormat {
+    Json,
+    Csv,
+}
+
+/// Direction the process-table selection moves when a navigation key is pressed.
+enum ScrollDirection {
+    Up,
+    Down,
+}
+
+/// Column the process table is ordered by. Always sorted descending.
+enum ProcessSortKey {
+    Cpu,
+    Memory,
+    Pid,
+}
+
+/// Incremental regex search state for the process table, entered with `/`.
+///
+/// The compiled pattern is cached in `Option<Result<…>>` form so a blank query
+/// matches everything and a malformed pattern is reported rather than panicking.
+struct AppSearchState {
+    is_enabled: bool,
+    query: String,
+    cursor: usize,
+    compiled: Option<Result<Regex, regex::Error>>,
+}
+
+impl AppSearchState {
+    /// Recompile `compiled` from the current query. A blank query is `None`
+    /// (match everything); otherwise the compile result is stored as-is.
+    fn recompile(&mut self) {
+        self.compiled = if self.query.is_empty() {
+            None
+        } else {
+            Some(Regex::new(&self.query))
+        };
+    }
+
+    /// Append a typed character to the query.
+    fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.cursor = self.query.len();
+        self.recompile();
+    }
+
+    /// Remove the character before the cursor.
+    fn backspace(&mut self) {
+        self.query.pop();
+        self.cursor = self.query.len();
+        self.recompile();
+    }
+
+    /// Whether `name` passes the current filter. A blank or invalid pattern
+    /// leaves every row visible.
+    fn matches(&self, name: &str) -> bool {
+        match &self.compiled {
+            None => true,
+            Some(Ok(re)) => re.is_match(name),
+            Some(Err(_)) => true,
+        }
+    }
+}
+
 struct AppState {
     total_operations: u64,
     elapsed_time: Duration,
@@ -31,31 +186,85 @@ struct AppState {
     memory_usage_history: Vec<(f64, f64)>,
     cpu_details: Vec<(String, f32, u64)>,
     system_info: Vec<(String, String)>,
+    processes: Vec<(u32, String, f32, f64)>,
+    selected_process: usize,
+    process_sort: ProcessSortKey,
+    is_frozen: bool,
+    time_window_secs: f64,
+    components: Vec<(String, f32)>,
+    temperature_unit: TemperatureType,
+    temp_threshold: f32,
+    search: AppSearchState,
+}
+
+/// Smallest chart time window the `+`/`-` keys will zoom in to, in seconds.
+const MIN_TIME_WINDOW_SECS: f64 = 5.0;
+/// How much a single `+`/`-` press changes the chart time window.
+const TIME_WINDOW_STEP_SECS: f64 = 5.0;
+
+impl AppState {
+    /// Build the initial application state, seeding the runtime-toggleable
+    /// fields from the parsed [`Config`].
+    fn new(config: &Config) -> Self {
+        AppState {
+            total_operations: 0,
+            elapsed_time: Duration::new(0, 0),
+            cpu_usage: 0.0,
+            memory_usage: 0.0,
+            cpu_usage_history: vec![],
+            memory_usage_history: vec![],
+            cpu_details: vec![],
+            system_info: vec![],
+            processes: vec![],
+            selected_process: 0,
+            process_sort: ProcessSortKey::Cpu,
+            is_frozen: false,
+            time_window_secs: 60.0,
+            components: vec![],
+            temperature_unit: config.temperature_unit,
+            temp_threshold: config.temp_threshold,
+            search: AppSearchState {
+                is_enabled: false,
+                query: String::new(),
+                cursor: 0,
+                compiled: None,
+            },
+        }
+    }
+
+    /// Move the process-table selection one row, clamped to the populated range.
+    fn scroll_processes(&mut self, direction: ScrollDirection) {
+        match direction {
+            ScrollDirection::Up => {
+                self.selected_process = self.selected_process.saturating_sub(1);
+            }
+            ScrollDirection::Down => {
+                if self.selected_process + 1 < self.processes.len() {
+                    self.selected_process += 1;
+                }
+            }
+        }
+    }
 }
 
 fn main() -> Result<(), io::Error> {
-    // Terminal initialization
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    let config = Config::parse();
 
-    let num_cores = num_cpus::get();
     let total_operations = Arc::new(AtomicU64::new(0));
     let start_time = Instant::now();
 
     let mut handles = vec![];
 
-    for _ in 0..num_cores {
+    for _ in 0..config.threads {
         let total_operations = total_operations.clone();
+        let target = config.operations;
         let handle = thread::spawn(move || {
             let mut n: u64 = 0;
             loop {
                 n = n.wrapping_add(1);
                 if n % 1_000_000 == 0 {
                     total_operations.fetch_add(1_000_000, Ordering::Relaxed);
-                    if total_operations.load(Ordering::Relaxed) >= TARGET_OPERATIONS {
+                    if total_operations.load(Ordering::Relaxed) >= target {
                         break;
                     }
                 }
@@ -64,19 +273,21 @@ fn main() -> Result<(), io::Error> {
         handles.push(handle);
     }
 
+    if config.headless {
+        return run_headless(&config, &total_operations, start_time, handles);
+    }
+
+    // Terminal initialization
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
     let mut sys = System::new_all();
     sysinfo::get_current_pid().expect("Failed to get current PID");
 
-    let mut app_state = AppState {
-        total_operations: 0,
-        elapsed_time: Duration::new(0, 0),
-        cpu_usage: 0.0,
-        memory_usage: 0.0,
-        cpu_usage_history: vec![],
-        memory_usage_history: vec![],
-        cpu_details: vec![],
-        system_info: vec![],
-    };
+    let mut app_state = AppState::new(&config);
 
     loop {
         terminal.draw(|f| {
@@ -93,15 +304,49 @@ fn main() -> Result<(), io::Error> {
                 )
                 .split(f.size());
 
-            render_header(f, chunks[0], &app_state);
+            render_header(f, chunks[0], &app_state, config.operations);
             render_charts(f, chunks[1], &app_state);
             render_details(f, chunks[2], &app_state);
         })?;
 
-        if event::poll(Duration::from_millis(200))? {
+        if event::poll(Duration::from_millis(config.refresh_ms))? {
             if let Event::Key(key) = event::read()? {
-                if let KeyCode::Char('q') = key.code {
-                    break;
+                if app_state.search.is_enabled {
+                    match key.code {
+                        KeyCode::Esc => app_state.search.is_enabled = false,
+                        KeyCode::Backspace => app_state.search.backspace(),
+                        KeyCode::Char(c) => app_state.search.push(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char('q') => break,
+                    KeyCode::Char('/') => app_state.search.is_enabled = true,
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        app_state.scroll_processes(ScrollDirection::Up)
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        app_state.scroll_processes(ScrollDirection::Down)
+                    }
+                    KeyCode::Char('c') => app_state.process_sort = ProcessSortKey::Cpu,
+                    KeyCode::Char('m') => app_state.process_sort = ProcessSortKey::Memory,
+                    KeyCode::Char('p') => app_state.process_sort = ProcessSortKey::Pid,
+                    KeyCode::Char(' ') | KeyCode::Char('f') => {
+                        app_state.is_frozen = !app_state.is_frozen
+                    }
+                    KeyCode::Char('+') | KeyCode::Char('=') => {
+                        app_state.time_window_secs = (app_state.time_window_secs
+                            - TIME_WINDOW_STEP_SECS)
+                            .max(MIN_TIME_WINDOW_SECS);
+                    }
+                    KeyCode::Char('-') => {
+                        app_state.time_window_secs += TIME_WINDOW_STEP_SECS;
+                    }
+                    KeyCode::Char('t') => {
+                        app_state.temperature_unit = app_state.temperature_unit.next_unit()
+                    }
+                    _ => {}
                 }
             }
         }
@@ -111,9 +356,10 @@ fn main() -> Result<(), io::Error> {
             &mut sys,
             total_operations.load(Ordering::Relaxed),
             start_time,
+            &config,
         );
 
-        if app_state.total_operations >= TARGET_OPERATIONS {
+        if app_state.total_operations >= config.operations {
             break;
         }
     }
@@ -132,16 +378,179 @@ fn main() -> Result<(), io::Error> {
     }
 
     let total_time = start_time.elapsed();
-    let operations_per_second = TARGET_OPERATIONS as f64 / total_time.as_secs_f64();
+    print_summary(&config, total_time);
+    if let Some(path) = &config.export {
+        export_results(&config, total_time, &app_state, path)?;
+    }
+
+    Ok(())
+}
+
+/// Run the benchmark without a TUI: sample the system each refresh interval so
+/// the export still has metrics, then print the score summary on completion.
+fn run_headless(
+    config: &Config,
+    total_operations: &Arc<AtomicU64>,
+    start_time: Instant,
+    handles: Vec<thread::JoinHandle<()>>,
+) -> Result<(), io::Error> {
+    let mut sys = System::new_all();
+    let mut app_state = AppState::new(config);
+
+    loop {
+        update_app_state(
+            &mut app_state,
+            &mut sys,
+            total_operations.load(Ordering::Relaxed),
+            start_time,
+            config,
+        );
+        if app_state.total_operations >= config.operations {
+            break;
+        }
+        thread::sleep(Duration::from_millis(config.refresh_ms));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let total_time = start_time.elapsed();
+    print_summary(config, total_time);
+    if let Some(path) = &config.export {
+        export_results(config, total_time, &app_state, path)?;
+    }
+
+    Ok(())
+}
+
+/// Print the final score summary for a completed run.
+fn print_summary(config: &Config, total_time: Duration) {
+    let operations_per_second = config.operations as f64 / total_time.as_secs_f64();
 
     println!("Stress test completed");
-    println!("Total operations: {}", TARGET_OPERATIONS);
+    println!("Total operations: {}", config.operations);
     println!("Total time: {:.2?}", total_time);
     println!("Operations per second: {:.2}", operations_per_second);
     println!(
         "Score: {:.2} million ops/sec",
         operations_per_second / 1_000_000.0
     );
+}
+
+/// Structured benchmark result, diffable across machines and over time.
+#[derive(Serialize)]
+struct ResultRecord {
+    total_operations: u64,
+    total_time_secs: f64,
+    operations_per_second: f64,
+    score_mops: f64,
+    threads: usize,
+    peak_cpu_percent: f64,
+    average_cpu_percent: f64,
+    peak_memory_percent: f64,
+    cpu_usage_history: Vec<(f64, f64)>,
+    memory_usage_history: Vec<(f64, f64)>,
+}
+
+impl ResultRecord {
+    /// Assemble a record from the collected state at the end of a run.
+    fn from_run(config: &Config, total_time: Duration, app_state: &AppState) -> Self {
+        let operations_per_second = config.operations as f64 / total_time.as_secs_f64();
+        let cpu_samples: Vec<f64> = app_state
+            .cpu_usage_history
+            .iter()
+            .map(|&(_, y)| y)
+            .collect();
+        let peak_cpu_percent = cpu_samples.iter().cloned().fold(0.0, f64::max);
+        let average_cpu_percent = if cpu_samples.is_empty() {
+            0.0
+        } else {
+            cpu_samples.iter().sum::<f64>() / cpu_samples.len() as f64
+        };
+        let peak_memory_percent = app_state
+            .memory_usage_history
+            .iter()
+            .map(|&(_, y)| y)
+            .fold(0.0, f64::max);
+
+        ResultRecord {
+            total_operations: config.operations,
+            total_time_secs: total_time.as_secs_f64(),
+            operations_per_second,
+            score_mops: operations_per_second / 1_000_000.0,
+            threads: config.threads,
+            peak_cpu_percent,
+            average_cpu_percent,
+            peak_memory_percent,
+            cpu_usage_history: app_state.cpu_usage_history.clone(),
+            memory_usage_history: app_state.memory_usage_history.clone(),
+        }
+    }
+}
+
+/// Serialize the run's results to `path`, choosing the format from `--format`
+/// or, failing that, the path's extension (defaulting to JSON).
+fn export_results(
+    config: &Config,
+    total_time: Duration,
+    app_state: &AppState,
+    path: &Path,
+) -> Result<(), io::Error> {
+    let record = ResultRecord::from_run(config, total_time, app_state);
+
+    let format = config.format.unwrap_or_else(|| {
+        match path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("csv") => ExportFormat::Csv,
+            _ => ExportFormat::Json,
+        }
+    });
+
+    let mut file = File::create(path)?;
+    match format {
+        ExportFormat::Json => {
+            let json = serde_json::to_string_pretty(&record)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            file.write_all(json.as_bytes())?;
+        }
+        ExportFormat::Csv => write_csv(&mut file, &record)?,
+    }
+
+    println!("Exported results to {}", path.display());
+    Ok(())
+}
+
+/// Hand-rolled CSV writer: a `metric,value` summary block followed by the
+/// per-sample `t_secs,cpu_percent,memory_percent` time series.
+fn write_csv(file: &mut File, record: &ResultRecord) -> Result<(), io::Error> {
+    writeln!(file, "metric,value")?;
+    writeln!(file, "total_operations,{}", record.total_operations)?;
+    writeln!(file, "total_time_secs,{}", record.total_time_secs)?;
+    writeln!(
+        file,
+        "operations_per_second,{}",
+        record.operations_per_second
+    )?;
+    writeln!(file, "score_mops,{}", record.score_mops)?;
+    writeln!(file, "threads,{}", record.threads)?;
+    writeln!(file, "peak_cpu_percent,{}", record.peak_cpu_percent)?;
+    writeln!(file, "average_cpu_percent,{}", record.average_cpu_percent)?;
+    writeln!(file, "peak_memory_percent,{}", record.peak_memory_percent)?;
+
+    writeln!(file)?;
+    writeln!(file, "t_secs,cpu_percent,memory_percent")?;
+    for (&(t, cpu), &(_, mem)) in record
+        .cpu_usage_history
+        .iter()
+        .zip(record.memory_usage_history.iter())
+    {
+        writeln!(file, "{},{},{}", t, cpu, mem)?;
+    }
 
     Ok(())
 }
@@ -150,10 +559,16 @@ fn render_header(
     f: &mut tui::Frame<CrosstermBackend<io::Stdout>>,
     area: Rect,
     app_state: &AppState,
+    target_operations: u64,
 ) {
-    let progress = app_state.total_operations as f64 / TARGET_OPERATIONS as f64;
+    let progress = app_state.total_operations as f64 / target_operations as f64;
+    let title = if app_state.is_frozen {
+        "Progress [FROZEN]"
+    } else {
+        "Progress"
+    };
     let gauge = Gauge::default()
-        .block(Block::default().title("Progress").borders(Borders::ALL))
+        .block(Block::default().title(title).borders(Borders::ALL))
         .gauge_style(Style::default().fg(Color::Cyan))
         .percent((progress * 100.0) as u16);
     f.render_widget(gauge, area);
@@ -191,6 +606,8 @@ fn render_charts(
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
         .split(area);
 
+    let (x_bounds, x_labels) = time_axis(app_state);
+
     let mut cpu_filled_data = Vec::new();
     let cpu_dataset = create_filled_dataset(
         &app_state.cpu_usage_history,
@@ -199,7 +616,7 @@ fn render_charts(
         &mut cpu_filled_data,
     );
     let binding = [cpu_dataset];
-    let cpu_chart = create_chart(&binding, "CPU Usage", [0.0, 60.0], [0.0, 100.0]);
+    let cpu_chart = create_chart(&binding, "CPU Usage", x_bounds, [0.0, 100.0], x_labels.clone());
     f.render_widget(cpu_chart, chunks[0]);
 
     let mut memory_filled_data = Vec::new();
@@ -210,15 +627,49 @@ fn render_charts(
         &mut memory_filled_data,
     );
     let binding = [memory_dataset];
-    let memory_chart = create_chart(&binding, "Memory Usage", [0.0, 60.0], [0.0, 100.0]);
+    let memory_chart = create_chart(&binding, "Memory Usage", x_bounds, [0.0, 100.0], x_labels);
     f.render_widget(memory_chart, chunks[1]);
 }
 
+/// Compute the charts' x-axis bounds and labels from the current zoom level.
+///
+/// The window ends at the latest retained sample and spans back
+/// `time_window_secs`, clamped between [`MIN_TIME_WINDOW_SECS`] and the full
+/// retained history so the zoom stays legible.
+fn time_axis(app_state: &AppState) -> ([f64; 2], Vec<Span<'static>>) {
+    let now = app_state
+        .cpu_usage_history
+        .last()
+        .map(|&(x, _)| x)
+        .unwrap_or(0.0);
+    let earliest = app_state
+        .cpu_usage_history
+        .first()
+        .map(|&(x, _)| x)
+        .unwrap_or(0.0);
+
+    let retained = (now - earliest).max(MIN_TIME_WINDOW_SECS);
+    let window = app_state
+        .time_window_secs
+        .clamp(MIN_TIME_WINDOW_SECS, retained);
+    let x_min = now - window;
+    let mid = x_min + window / 2.0;
+
+    let labels = vec![
+        Span::raw(format!("{:.0}s", x_min)),
+        Span::raw(format!("{:.0}s", mid)),
+        Span::raw(format!("{:.0}s", now)),
+    ];
+
+    ([x_min, now], labels)
+}
+
 fn create_chart<'a>(
     datasets: &'a [Dataset],
     title: &'a str,
     x_bounds: [f64; 2],
     y_bounds: [f64; 2],
+    x_labels: Vec<Span<'a>>,
 ) -> Chart<'a> {
     Chart::new(datasets.to_vec())
         .block(Block::default().title(title).borders(Borders::ALL))
@@ -226,12 +677,7 @@ fn create_chart<'a>(
             Axis::default()
                 .style(Style::default().fg(Color::Gray))
                 .bounds(x_bounds)
-                .labels(
-                    [" ", " ", " ", " ", " "]
-                        .iter()
-                        .map(|&s| s.into())
-                        .collect(),
-                ),
+                .labels(x_labels),
         )
         .y_axis(
             Axis::default()
@@ -255,9 +701,11 @@ fn render_details(
         .direction(Direction::Horizontal)
         .constraints(
             [
-                Constraint::Percentage(33),
-                Constraint::Percentage(33),
-                Constraint::Percentage(34),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
             ]
             .as_ref(),
         )
@@ -266,6 +714,102 @@ fn render_details(
     render_stats(f, chunks[0], app_state);
     render_cpu_details(f, chunks[1], app_state);
     render_system_info(f, chunks[2], app_state);
+    render_processes(f, chunks[3], app_state);
+    render_temperatures(f, chunks[4], app_state);
+}
+
+fn render_temperatures(
+    f: &mut tui::Frame<CrosstermBackend<io::Stdout>>,
+    area: Rect,
+    app_state: &AppState,
+) {
+    let unit = app_state.temperature_unit;
+    let items: Vec<ListItem> = app_state
+        .components
+        .iter()
+        .map(|(label, celsius)| {
+            let color = if *celsius >= app_state.temp_threshold {
+                Color::Red
+            } else {
+                Color::Cyan
+            };
+            ListItem::new(Spans::from(vec![
+                Span::raw(format!("{}: ", label)),
+                Span::styled(
+                    format!("{:.1}°{}", unit.convert(*celsius), unit.suffix()),
+                    Style::default().fg(color),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!("Temperatures (°{})", unit.suffix()))
+                .borders(Borders::ALL),
+        )
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .highlight_symbol("> ");
+
+    f.render_widget(list, area);
+}
+
+fn render_processes(
+    f: &mut tui::Frame<CrosstermBackend<io::Stdout>>,
+    area: Rect,
+    app_state: &AppState,
+) {
+    let filtered: Vec<&(u32, String, f32, f64)> = app_state
+        .processes
+        .iter()
+        .filter(|(_, name, _, _)| !app_state.search.is_enabled || app_state.search.matches(name))
+        .collect();
+
+    let items: Vec<ListItem> = filtered
+        .iter()
+        .map(|(pid, name, cpu, mem)| {
+            ListItem::new(Spans::from(vec![
+                Span::raw(format!("{:>6} {:<12} ", pid, name)),
+                Span::styled(
+                    format!("{:.1}% {:.1} MB", cpu, mem),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ]))
+        })
+        .collect();
+
+    let sort_label = match app_state.process_sort {
+        ProcessSortKey::Cpu => "CPU",
+        ProcessSortKey::Memory => "Mem",
+        ProcessSortKey::Pid => "PID",
+    };
+
+    // When searching, show the live query; flag a pattern that fails to compile.
+    let title = if app_state.search.is_enabled {
+        let invalid = matches!(app_state.search.compiled, Some(Err(_)));
+        Span::styled(
+            format!(
+                "/{}{}",
+                app_state.search.query,
+                if invalid { " (invalid search)" } else { "" }
+            ),
+            Style::default().fg(if invalid { Color::Red } else { Color::White }),
+        )
+    } else {
+        Span::raw(format!("Processes (sort: {})", sort_label))
+    };
+
+    let list = List::new(items)
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .highlight_symbol("> ");
+
+    let mut state = ListState::default();
+    if !filtered.is_empty() {
+        state.select(Some(app_state.selected_process.min(filtered.len() - 1)));
+    }
+    f.render_stateful_widget(list, area, &mut state);
 }
 
 fn render_stats(
@@ -370,6 +914,7 @@ fn update_app_state(
     sys: &mut System,
     total_operations: u64,
     start_time: Instant,
+    config: &Config,
 ) {
     sys.refresh_all();
 
@@ -384,6 +929,13 @@ fn update_app_state(
     let used_memory = sys.used_memory();
     app_state.memory_usage = (used_memory as f64 / total_memory as f64) * 100.0;
 
+    // While frozen, keep the counter, timer and current CPU/memory readings
+    // advancing but leave the charts, CPU details, processes, temperatures and
+    // system info on their last sampled frame so they can be read.
+    if app_state.is_frozen {
+        return;
+    }
+
     let elapsed_seconds = app_state.elapsed_time.as_secs_f64();
     let cpu_usage = (app_state.cpu_usage as f64 * 100.0).round() / 100.0; // Round to nearest percentage
     let memory_usage = (app_state.memory_usage * 100.0).round() / 100.0; // Round to nearest percentage
@@ -395,7 +947,7 @@ fn update_app_state(
         .memory_usage_history
         .push((elapsed_seconds, memory_usage));
 
-    if app_state.cpu_usage_history.len() > 240 {
+    if app_state.cpu_usage_history.len() > config.history {
         app_state.cpu_usage_history.remove(0);
         app_state.memory_usage_history.remove(0);
     }
@@ -407,6 +959,38 @@ fn update_app_state(
         .map(|(i, cpu)| (format!("CPU {}", i), cpu.cpu_usage(), cpu.frequency()))
         .collect();
 
+    let mut processes: Vec<(u32, String, f32, f64)> = sys
+        .processes()
+        .values()
+        .map(|process| {
+            (
+                process.pid().as_u32(),
+                process.name().to_string(),
+                process.cpu_usage(),
+                process.memory() as f64 / 1024.0 / 1024.0,
+            )
+        })
+        .collect();
+    match app_state.process_sort {
+        ProcessSortKey::Cpu => {
+            processes.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal))
+        }
+        ProcessSortKey::Memory => {
+            processes.sort_by(|a, b| b.3.partial_cmp(&a.3).unwrap_or(std::cmp::Ordering::Equal))
+        }
+        ProcessSortKey::Pid => processes.sort_by(|a, b| b.0.cmp(&a.0)),
+    }
+    app_state.processes = processes;
+    if app_state.selected_process >= app_state.processes.len() {
+        app_state.selected_process = app_state.processes.len().saturating_sub(1);
+    }
+
+    app_state.components = sys
+        .components()
+        .iter()
+        .map(|component| (component.label().to_string(), component.temperature()))
+        .collect();
+
     app_state.system_info = vec![
         ("OS".to_string(), sys.name().unwrap_or_default()),
         (